@@ -3,4 +3,6 @@
 set -eu
 
 cargo test --all "$@"
+cargo build --no-default-features "$@"
+cargo test -p no-std-check "$@"
 cargo test --all "$@" --features="default serialize"
@@ -1,19 +1,50 @@
-use std::cmp::Ordering;
-use std::convert::TryInto;
-use std::fmt;
-use std::time::Duration;
-use std::ops::{Add, AddAssign, Sub, SubAssign};
-
-use winapi::{
-    shared::minwindef::{FILETIME, DWORD},
-    um::sysinfoapi::GetSystemTimePreciseAsFileTime,
-};
+use core::cmp::Ordering;
+use core::convert::TryInto;
+use core::fmt;
+use core::str::FromStr;
+use core::time::Duration;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+#[cfg(feature = "std")]
+use std::{sync::Mutex, time::SystemTime};
+
+#[cfg(windows)]
+use winapi::shared::minwindef::{FILETIME, DWORD};
+
+/// Mirror of the Windows `FILETIME` layout so every platform shares the same
+/// 100-nanosecond-intervals-since-1601 representation.
+#[cfg(not(windows))]
+#[allow(non_snake_case, clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Default)]
+struct FILETIME {
+    dwLowDateTime: DWORD,
+    dwHighDateTime: DWORD,
+}
+
+#[cfg(not(windows))]
+#[allow(clippy::upper_case_acronyms)]
+type DWORD = u32;
 
 use core::hash::{Hash, Hasher};
 
+#[cfg(all(windows, feature = "std"))]
+use winapi::um::{
+    profileapi::{QueryPerformanceCounter, QueryPerformanceFrequency},
+    sysinfoapi::{GetSystemTimeAsFileTime, GetSystemTimePreciseAsFileTime},
+    winnt::LARGE_INTEGER,
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 const NANOS_PER_SEC: u64 = 1_000_000_000;
 const INTERVALS_PER_SEC: u64 = NANOS_PER_SEC / 100;
 
+/// A point in time stored as a signed count of 100-nanosecond intervals since
+/// 1601-01-01 00:00:00 UTC.
+///
+/// Negative and pre-1970 values are legitimate: anything from roughly 27,000 BC to 30,000 AD can
+/// be represented, including times before `UNIX_EPOCH`.
 #[derive(Copy, Clone)]
 pub struct KrazyKraigTime {
     t: FILETIME,
@@ -21,6 +52,9 @@ pub struct KrazyKraigTime {
 
 const INTERVALS_TO_UNIX_EPOCH: u64 = 11_644_473_600 * INTERVALS_PER_SEC;
 
+const INTERVALS_PER_DAY: i64 = 86_400 * INTERVALS_PER_SEC as i64;
+const DAYS_TO_UNIX_EPOCH: i64 = 134_774;
+
 #[derive(Debug)]
 pub struct SystemTimeError(Duration);
 
@@ -31,7 +65,9 @@ pub const UNIX_EPOCH: KrazyKraigTime = KrazyKraigTime {
     },
 };
 
+#[cfg(feature = "std")]
 impl KrazyKraigTime {
+    #[cfg(windows)]
     pub fn now() -> KrazyKraigTime {
         unsafe {
             let mut t = FILETIME::default();
@@ -40,26 +76,74 @@ impl KrazyKraigTime {
         }
     }
 
+    #[cfg(unix)]
+    pub fn now() -> KrazyKraigTime {
+        KrazyKraigTime::from_timespec(clock_gettime(libc::CLOCK_REALTIME))
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    pub fn now() -> KrazyKraigTime {
+        let since_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system clock is before the unix epoch");
+        UNIX_EPOCH
+            .checked_add_duration(&since_epoch)
+            .expect("overflow when converting system time")
+    }
+
+    /// Reads the wall clock at the resolution of the system timer tick, see
+    /// `TimeStamp::now_coarse`.
+    pub fn now_coarse() -> KrazyKraigTime {
+        #[cfg(windows)]
+        let coarse = unsafe {
+            let mut t = FILETIME::default();
+            GetSystemTimeAsFileTime(&mut t);
+            KrazyKraigTime { t }
+        };
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let coarse = KrazyKraigTime::from_timespec(clock_gettime(libc::CLOCK_REALTIME_COARSE));
+        #[cfg(not(any(windows, target_os = "linux", target_os = "android")))]
+        let coarse = KrazyKraigTime::now();
+        coarse
+    }
+
+    #[cfg(unix)]
+    fn from_timespec(ts: libc::timespec) -> KrazyKraigTime {
+        KrazyKraigTime::from_unix_parts(i128::from(ts.tv_sec), i128::from(ts.tv_nsec))
+    }
+}
+
+impl KrazyKraigTime {
+    // The dwords hold the two halves of the two's complement bit pattern, so round trip through
+    // `u64` with explicit masking to keep negative interval counts intact.
     fn from_intervals(intervals: i64) -> KrazyKraigTime {
+        let bits = intervals as u64;
         KrazyKraigTime {
             t: FILETIME {
-                dwLowDateTime: intervals as DWORD,
-                dwHighDateTime: (intervals >> 32) as DWORD,
+                dwLowDateTime: (bits & 0xFFFF_FFFF) as DWORD,
+                dwHighDateTime: (bits >> 32) as DWORD,
             },
         }
     }
 
     fn intervals(&self) -> i64 {
-        (self.t.dwLowDateTime as i64) | ((self.t.dwHighDateTime as i64) << 32)
+        (u64::from(self.t.dwLowDateTime) | (u64::from(self.t.dwHighDateTime) << 32)) as i64
+    }
+
+    // Signed 100-nanosecond intervals since the Unix epoch. Unlike `TimeStamp::as_unix_nanos`
+    // this never saturates, so distinct instants always have distinct values.
+    fn unix_intervals(&self) -> i128 {
+        i128::from(self.intervals()) - i128::from(INTERVALS_TO_UNIX_EPOCH)
     }
 
     pub fn sub_time(&self, other: &KrazyKraigTime) -> Result<Duration, Duration> {
         let me = self.intervals();
         let other = other.intervals();
+        // The difference of any two `i64`s fits in a `u64`, even where it overflows `i64`.
         if me >= other {
-            Ok(intervals2dur((me - other) as u64))
+            Ok(intervals2dur(me.wrapping_sub(other) as u64))
         } else {
-            Err(intervals2dur((other - me) as u64))
+            Err(intervals2dur(other.wrapping_sub(me) as u64))
         }
     }
 
@@ -72,11 +156,33 @@ impl KrazyKraigTime {
         let intervals = self.intervals().checked_sub(checked_dur2intervals(other)?)?;
         Some(KrazyKraigTime::from_intervals(intervals))
     }
+
+    pub fn saturating_add_duration(&self, other: &Duration) -> KrazyKraigTime {
+        KrazyKraigTime::from_wide_intervals(i128::from(self.intervals()) + wide_dur2intervals(other))
+    }
+
+    pub fn saturating_sub_duration(&self, other: &Duration) -> KrazyKraigTime {
+        KrazyKraigTime::from_wide_intervals(i128::from(self.intervals()) - wide_dur2intervals(other))
+    }
+
+    fn from_wide_intervals(intervals: i128) -> KrazyKraigTime {
+        KrazyKraigTime::from_intervals(intervals.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64)
+    }
+
+    // Seconds plus nanoseconds since the Unix epoch, saturating at the representable bounds.
+    // `nanos` may fall outside a single second and is floored to 100-nanosecond intervals.
+    fn from_unix_parts(secs: i128, nanos: i128) -> KrazyKraigTime {
+        KrazyKraigTime::from_wide_intervals(
+            secs * i128::from(INTERVALS_PER_SEC) + nanos.div_euclid(100) + i128::from(INTERVALS_TO_UNIX_EPOCH),
+        )
+    }
 }
 
+// Equality, ordering and hashing all go through this one backend-independent key, so equal
+// instants are interchangeable however they were constructed.
 impl PartialEq for KrazyKraigTime {
     fn eq(&self, other: &KrazyKraigTime) -> bool {
-        self.intervals() == other.intervals()
+        self.unix_intervals() == other.unix_intervals()
     }
 }
 
@@ -90,7 +196,7 @@ impl PartialOrd for KrazyKraigTime {
 
 impl Ord for KrazyKraigTime {
     fn cmp(&self, other: &KrazyKraigTime) -> Ordering {
-        self.intervals().cmp(&other.intervals())
+        self.unix_intervals().cmp(&other.unix_intervals())
     }
 }
 
@@ -100,6 +206,7 @@ impl fmt::Debug for KrazyKraigTime {
     }
 }
 
+#[cfg(windows)]
 impl From<FILETIME> for KrazyKraigTime {
     fn from(t: FILETIME) -> KrazyKraigTime {
         KrazyKraigTime { t }
@@ -108,10 +215,18 @@ impl From<FILETIME> for KrazyKraigTime {
 
 impl Hash for KrazyKraigTime {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.intervals().hash(state)
+        self.unix_intervals().hash(state)
     }
 }
 
+#[cfg(all(unix, feature = "std"))]
+fn clock_gettime(clock: libc::clockid_t) -> libc::timespec {
+    let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0 };
+    let ret = unsafe { libc::clock_gettime(clock, &mut ts) };
+    assert_eq!(ret, 0, "clock_gettime failed");
+    ts
+}
+
 fn checked_dur2intervals(dur: &Duration) -> Option<i64> {
     dur.as_secs()
         .checked_mul(INTERVALS_PER_SEC)?
@@ -120,6 +235,10 @@ fn checked_dur2intervals(dur: &Duration) -> Option<i64> {
         .ok()
 }
 
+fn wide_dur2intervals(dur: &Duration) -> i128 {
+    i128::from(dur.as_secs()) * i128::from(INTERVALS_PER_SEC) + i128::from(dur.subsec_nanos() / 100)
+}
+
 fn intervals2dur(intervals: u64) -> Duration {
     Duration::new(intervals / INTERVALS_PER_SEC, ((intervals % INTERVALS_PER_SEC) * 100) as u32)
 }
@@ -133,6 +252,171 @@ impl fmt::Debug for TimeStamp {
     }
 }
 
+impl fmt::Display for TimeStamp {
+    /// Formats the timestamp as an RFC 3339 UTC date-time, e.g. `2024-05-01T12:34:56.789Z`.
+    ///
+    /// Fractional seconds are printed up to 100-nanosecond precision with trailing zeros removed.
+    ///
+    /// RFC 3339 only covers the years 0000 to 9999. `TimeStamp`s outside that range print with a
+    /// sign or more than four year digits, and `FromStr` does not parse those back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let intervals = self.0.intervals();
+        let (year, month, day) = civil_from_days(intervals.div_euclid(INTERVALS_PER_DAY) - DAYS_TO_UNIX_EPOCH);
+        let of_day = intervals.rem_euclid(INTERVALS_PER_DAY);
+        let secs = of_day / INTERVALS_PER_SEC as i64;
+        let fraction = of_day % INTERVALS_PER_SEC as i64;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )?;
+        if fraction != 0 {
+            let mut digits = 7;
+            let mut fraction = fraction;
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{:0width$}", fraction, width = digits)?;
+        }
+        write!(f, "Z")
+    }
+}
+
+impl FromStr for TimeStamp {
+    type Err = ParseTimeStampError;
+
+    /// Parses an RFC 3339 date-time such as `2024-05-01T12:34:56.789Z` or
+    /// `2024-05-01T14:34:56.789+02:00`.
+    ///
+    /// A timezone designator is required. Fractional seconds beyond 100-nanosecond precision are
+    /// truncated.
+    ///
+    /// Only four-digit years (0000 to 9999) are accepted. Leap seconds (`:60`) are rejected, even
+    /// though RFC 3339 allows them.
+    fn from_str(s: &str) -> Result<TimeStamp, ParseTimeStampError> {
+        parse_rfc3339(s.as_bytes())
+            .map(|intervals| TimeStamp(KrazyKraigTime::from_intervals(intervals)))
+            .ok_or(ParseTimeStampError(()))
+    }
+}
+
+/// An error returned when parsing a `TimeStamp` from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseTimeStampError(());
+
+impl fmt::Display for ParseTimeStampError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid RFC 3339 timestamp")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseTimeStampError {}
+
+fn parse_rfc3339(s: &[u8]) -> Option<i64> {
+    if s.len() < 20 || s[4] != b'-' || s[7] != b'-' || !matches!(s[10], b'T' | b't') || s[13] != b':' || s[16] != b':' {
+        return None;
+    }
+    let year = parse_digits(&s[0..4])?;
+    let month = parse_digits(&s[5..7])?;
+    let day = parse_digits(&s[8..10])?;
+    let hour = parse_digits(&s[11..13])?;
+    let minute = parse_digits(&s[14..16])?;
+    let second = parse_digits(&s[17..19])?;
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut fraction = 0;
+    if rest[0] == b'.' {
+        let len = rest[1..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let digits = &rest[1..1 + len];
+        for i in 0..7 {
+            fraction = fraction * 10 + digits.get(i).map_or(0, |b| i64::from(b - b'0'));
+        }
+        rest = &rest[1 + len..];
+    }
+
+    let offset = match rest {
+        [b'Z'] | [b'z'] => 0,
+        [sign @ b'+', h1, h2, b':', m1, m2] | [sign @ b'-', h1, h2, b':', m1, m2] => {
+            let hours = parse_digits(&[*h1, *h2])?;
+            let minutes = parse_digits(&[*m1, *m2])?;
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+
+    let days = days_from_civil(year, month, day) + DAYS_TO_UNIX_EPOCH;
+    let secs = hour * 3600 + minute * 60 + second - offset;
+    days.checked_mul(INTERVALS_PER_DAY)?
+        .checked_add(secs * INTERVALS_PER_SEC as i64)?
+        .checked_add(fraction)
+}
+
+fn parse_digits(digits: &[u8]) -> Option<i64> {
+    digits.iter().try_fold(0, |acc, b| {
+        if b.is_ascii_digit() {
+            Some(acc * 10 + i64::from(b - b'0'))
+        } else {
+            None
+        }
+    })
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// Days since 1970-01-01 in the proleptic Gregorian calendar, after Howard Hinnant's
+// `days_from_civil`.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// Inverse of `days_from_civil`, returning `(year, month, day)`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
 impl Add<Duration> for TimeStamp {
     type Output = TimeStamp;
 
@@ -169,17 +453,109 @@ impl SubAssign<Duration> for TimeStamp {
     }
 }
 
+impl Sub<TimeStamp> for TimeStamp {
+    type Output = i64;
+
+    /// Returns the signed number of nanoseconds from `other` to `self`, negative when `other` is
+    /// the later of the two.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the difference does not fit in an `i64` of nanoseconds.
+    fn sub(self, other: TimeStamp) -> i64 {
+        self.0
+            .intervals()
+            .checked_sub(other.0.intervals())
+            .and_then(|intervals| intervals.checked_mul(100))
+            .expect("overflow when subtracting instants")
+    }
+}
+
+impl Add<Duration> for &TimeStamp {
+    type Output = TimeStamp;
+
+    /// # Panics
+    ///
+    /// Panics under the same conditions as adding to a `TimeStamp` by value.
+    fn add(self, dur: Duration) -> TimeStamp {
+        *self + dur
+    }
+}
+
+impl Sub<Duration> for &TimeStamp {
+    type Output = TimeStamp;
+
+    /// # Panics
+    ///
+    /// Panics under the same conditions as subtracting from a `TimeStamp` by value.
+    fn sub(self, dur: Duration) -> TimeStamp {
+        *self - dur
+    }
+}
+
+impl Sub<TimeStamp> for &TimeStamp {
+    type Output = i64;
+
+    /// # Panics
+    ///
+    /// Panics under the same conditions as subtracting two `TimeStamp`s by value.
+    fn sub(self, other: TimeStamp) -> i64 {
+        *self - other
+    }
+}
+
+impl Default for TimeStamp {
+    /// Returns `TimeStamp::UNIX_EPOCH`.
+    fn default() -> TimeStamp {
+        TimeStamp::UNIX_EPOCH
+    }
+}
+
 impl TimeStamp {
+    /// 1970-01-01 00:00:00 UTC, the zero value for `TimeStamp`.
     pub const UNIX_EPOCH: TimeStamp = TimeStamp(UNIX_EPOCH);
 
+    /// Creates a `TimeStamp` from a number of 100-nanosecond intervals since
+    /// 1601-01-01 00:00:00 UTC, the Windows `FILETIME` epoch.
+    pub fn from_intervals(intervals: i64) -> TimeStamp {
+        TimeStamp(KrazyKraigTime::from_intervals(intervals))
+    }
+
+    #[cfg(feature = "std")]
     pub fn now() -> TimeStamp {
         Self(KrazyKraigTime::now())
     }
 
+    /// Reads the wall clock at the resolution of the system timer tick rather than with the
+    /// precise-time machinery used by [`now`], which is cheaper when timestamping at a very high
+    /// rate.
+    ///
+    /// This uses `GetSystemTimeAsFileTime` on Windows and `CLOCK_REALTIME_COARSE` on Linux and
+    /// Android. The result may lag [`now`] by up to one tick (typically 1-16 ms depending on the
+    /// platform), so it is unsuitable for measuring short durations. Platforms without a coarse
+    /// clock, such as macOS, fall back to [`now`].
+    ///
+    /// [`now`]: #method.now
+    #[cfg(feature = "std")]
+    pub fn now_coarse() -> TimeStamp {
+        Self(KrazyKraigTime::now_coarse())
+    }
+
     pub fn duration_since(&self, earlier: TimeStamp) -> Result<Duration, SystemTimeError> {
         self.0.sub_time(&earlier.0).map_err(SystemTimeError)
     }
 
+    /// Returns the signed number of nanoseconds from `earlier` to `self`, negative when `self`
+    /// is the earlier of the two, e.g. for an event that happened before its reference point.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the difference does not fit in an `i64` of nanoseconds.
+    pub fn signed_duration_since(&self, earlier: TimeStamp) -> i64 {
+        *self - earlier
+    }
+
+    #[cfg(feature = "std")]
     pub fn elapsed(&self) -> Result<Duration, SystemTimeError> {
         TimeStamp::now().duration_since(*self)
     }
@@ -191,6 +567,254 @@ impl TimeStamp {
     pub fn checked_sub(&self, duration: Duration) -> Option<TimeStamp> {
         self.0.checked_sub_duration(&duration).map(TimeStamp)
     }
+
+    /// Adds `duration`, clamping at the latest representable `TimeStamp` instead of overflowing.
+    pub fn saturating_add(&self, duration: Duration) -> TimeStamp {
+        TimeStamp(self.0.saturating_add_duration(&duration))
+    }
+
+    /// Subtracts `duration`, clamping at the earliest representable `TimeStamp` instead of
+    /// overflowing.
+    pub fn saturating_sub(&self, duration: Duration) -> TimeStamp {
+        TimeStamp(self.0.saturating_sub_duration(&duration))
+    }
+
+    /// Returns the number of nanoseconds since the Unix epoch, as used by OTLP `fixed64`
+    /// timestamp fields.
+    ///
+    /// Timestamps are stored as 100-nanosecond intervals (the Windows `FILETIME` resolution) on
+    /// every platform, so the result is always a multiple of 100. Times before the Unix epoch
+    /// saturate to `0` and times beyond the `u64` range saturate to `u64::MAX`.
+    pub fn as_unix_nanos(&self) -> u64 {
+        let intervals = self.0.intervals().saturating_sub(INTERVALS_TO_UNIX_EPOCH as i64);
+        if intervals < 0 {
+            0
+        } else {
+            (intervals as u64).saturating_mul(100)
+        }
+    }
+
+    /// Returns the whole seconds since the Unix epoch, rounded towards negative infinity so that
+    /// [`subsec_nanos`] is always non-negative.
+    ///
+    /// [`subsec_nanos`]: #method.subsec_nanos
+    pub fn unix_seconds(&self) -> i64 {
+        self.0.unix_intervals().div_euclid(i128::from(INTERVALS_PER_SEC)) as i64
+    }
+
+    /// Returns the nanoseconds past [`unix_seconds`], in the range `0..1_000_000_000`.
+    ///
+    /// [`unix_seconds`]: #method.unix_seconds
+    pub fn subsec_nanos(&self) -> u32 {
+        self.0.unix_intervals().rem_euclid(i128::from(INTERVALS_PER_SEC)) as u32 * 100
+    }
+
+    /// Returns the time elapsed since the Unix epoch, or zero for earlier timestamps.
+    pub fn duration_since_epoch(&self) -> Duration {
+        self.duration_since(TimeStamp::UNIX_EPOCH).unwrap_or_default()
+    }
+
+    /// Returns the `(seconds, nanos)` pair of a protobuf `google.protobuf.Timestamp`, with
+    /// `nanos` in `0..1_000_000_000` even for times before the Unix epoch.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_proto_parts(&self) -> (i64, i32) {
+        (self.unix_seconds(), self.subsec_nanos() as i32)
+    }
+
+    /// Creates a `TimeStamp` from the fields of a protobuf `google.protobuf.Timestamp`.
+    ///
+    /// Out-of-range `nanos` are normalized into the seconds, so `(0, 1_500_000_000)` and
+    /// `(1, 500_000_000)` are the same instant. Sub-100-nanosecond precision is truncated and
+    /// values beyond the range of `TimeStamp` saturate at its bounds.
+    pub fn from_proto_parts(seconds: i64, nanos: i32) -> TimeStamp {
+        TimeStamp(KrazyKraigTime::from_unix_parts(i128::from(seconds), i128::from(nanos)))
+    }
+
+    /// Creates a `TimeStamp` from a number of nanoseconds since the Unix epoch.
+    ///
+    /// The value is truncated to 100-nanosecond granularity.
+    pub fn from_unix_nanos(nanos: u64) -> TimeStamp {
+        let intervals = (nanos / 100) as i64 + INTERVALS_TO_UNIX_EPOCH as i64;
+        TimeStamp(KrazyKraigTime::from_intervals(intervals))
+    }
+}
+
+/// Serialized as a `u64` of nanoseconds since the Unix epoch, see [`TimeStamp::as_unix_nanos`].
+///
+/// Timestamps before the Unix epoch serialize as `0`, so they do not survive a round trip.
+#[cfg(feature = "serialize")]
+impl Serialize for TimeStamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_unix_nanos().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for TimeStamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TimeStamp, D::Error> {
+        u64::deserialize(deserializer).map(TimeStamp::from_unix_nanos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<SystemTime> for TimeStamp {
+    /// Times outside the range of `TimeStamp` saturate at its bounds. Sub-100-nanosecond
+    /// precision is floored, as in [`TimeStamp::from_proto_parts`], on both sides of the epoch.
+    fn from(time: SystemTime) -> TimeStamp {
+        let (secs, nanos) = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(since_epoch) => (i128::from(since_epoch.as_secs()), i128::from(since_epoch.subsec_nanos())),
+            Err(err) => (-i128::from(err.duration().as_secs()), -i128::from(err.duration().subsec_nanos())),
+        };
+        TimeStamp(KrazyKraigTime::from_unix_parts(secs, nanos))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<TimeStamp> for SystemTime {
+    /// Timestamps before the Unix epoch saturate to `SystemTime::UNIX_EPOCH`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the platform's `SystemTime` cannot represent the timestamp. `SystemTime` covers
+    /// the whole range of `TimeStamp` on Windows and on Unix platforms with a 64-bit `time_t`.
+    fn from(timestamp: TimeStamp) -> SystemTime {
+        SystemTime::UNIX_EPOCH
+            .checked_add(timestamp.duration_since_epoch())
+            .expect("TimeStamp is within the range of SystemTime")
+    }
+}
+
+/// A reading of a monotonic clock, for measuring elapsed time.
+///
+/// Unlike `TimeStamp`, which follows the wall clock and may jump backwards when the system time
+/// is adjusted, a `MonoInstant` never decreases. It is only meaningful relative to other
+/// `MonoInstant`s and deliberately offers no conversion to wall-clock or Unix time.
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MonoInstant(Duration);
+
+#[cfg(feature = "std")]
+impl MonoInstant {
+    /// Reads the monotonic clock (`QueryPerformanceCounter` on Windows, `CLOCK_MONOTONIC` on unix).
+    #[cfg(windows)]
+    pub fn now() -> MonoInstant {
+        let (ticks, frequency) = unsafe {
+            let mut ticks = LARGE_INTEGER::default();
+            let mut frequency = LARGE_INTEGER::default();
+            QueryPerformanceCounter(&mut ticks);
+            QueryPerformanceFrequency(&mut frequency);
+            (*ticks.QuadPart() as u64, *frequency.QuadPart() as u64)
+        };
+        let nanos = (ticks % frequency) * NANOS_PER_SEC / frequency;
+        MonoInstant(Duration::new(ticks / frequency, nanos as u32))
+    }
+
+    /// Reads the monotonic clock (`QueryPerformanceCounter` on Windows, `CLOCK_MONOTONIC` on unix).
+    #[cfg(unix)]
+    #[allow(clippy::unnecessary_cast)]
+    pub fn now() -> MonoInstant {
+        let ts = clock_gettime(libc::CLOCK_MONOTONIC);
+        MonoInstant(Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32))
+    }
+
+    /// Reads the monotonic clock (`QueryPerformanceCounter` on Windows, `CLOCK_MONOTONIC` on unix).
+    #[cfg(not(any(unix, windows)))]
+    pub fn now() -> MonoInstant {
+        lazy_static::lazy_static! {
+            static ref ORIGIN: std::time::Instant = std::time::Instant::now();
+        }
+        MonoInstant(ORIGIN.elapsed())
+    }
+
+    /// Returns the time elapsed since this instant was read.
+    pub fn elapsed(&self) -> Duration {
+        MonoInstant::now().duration_since(*self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl MonoInstant {
+    /// Returns the time elapsed since `earlier`, or zero if `earlier` is later than `self`.
+    pub fn duration_since(&self, earlier: MonoInstant) -> Duration {
+        self.0.checked_sub(earlier.0).unwrap_or_default()
+    }
+}
+
+/// A source of `TimeStamp`s.
+///
+/// Code that needs the current time can take a `&dyn Clock` instead of calling
+/// `TimeStamp::now()` directly, so tests can substitute a `ManualClock`.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current time according to this clock.
+    fn now(&self) -> TimeStamp;
+}
+
+/// A `Clock` that reads the operating system's wall clock.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> TimeStamp {
+        TimeStamp::now()
+    }
+}
+
+/// A `Clock` that only moves when told to, and never consults the operating system.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<TimeStamp>,
+}
+
+#[cfg(feature = "std")]
+impl ManualClock {
+    /// Create a new `ManualClock` reading `start`.
+    pub fn new(start: TimeStamp) -> Self {
+        ManualClock { now: Mutex::new(start) }
+    }
+
+    /// Move the clock forward by `duration`.
+    ///
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be represented by the
+    /// underlying data structure.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for ManualClock {
+    fn now(&self) -> TimeStamp {
+        *self.now.lock().unwrap()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for TimeStamp {
+    /// Sub-100-nanosecond precision is truncated, and values outside the range of `TimeStamp`
+    /// saturate at its bounds.
+    fn from(time: chrono::DateTime<chrono::Utc>) -> TimeStamp {
+        TimeStamp(KrazyKraigTime::from_unix_parts(
+            i128::from(time.timestamp()),
+            i128::from(time.timestamp_subsec_nanos()),
+        ))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<TimeStamp> for chrono::DateTime<chrono::Utc> {
+    fn from(timestamp: TimeStamp) -> chrono::DateTime<chrono::Utc> {
+        use chrono::TimeZone;
+
+        chrono::Utc
+            .timestamp_opt(timestamp.unix_seconds(), timestamp.subsec_nanos())
+            .single()
+            .expect("TimeStamp is within the range of chrono::DateTime")
+    }
 }
 
 impl SystemTimeError {
@@ -199,15 +823,12 @@ impl SystemTimeError {
     }
 }
 
-impl std::error::Error for SystemTimeError {
-    fn description(&self) -> &str {
-        "other time was not earlier than self"
-    }
-}
+#[cfg(feature = "std")]
+impl std::error::Error for SystemTimeError {}
 
 impl fmt::Display for SystemTimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "second time provided was later than self")
+        write!(f, "second time provided was later than self by {:?}", self.0)
     }
 }
 
@@ -217,4 +838,355 @@ impl FromInner<SystemTime> for SystemTime {
         SystemTime(time)
     }
 }
-*/
\ No newline at end of file
+*/
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unix_epoch_intervals() {
+        assert_eq!(UNIX_EPOCH.intervals(), INTERVALS_TO_UNIX_EPOCH as i64);
+        assert_eq!(TimeStamp::UNIX_EPOCH.0.intervals(), 116_444_736_000_000_000);
+    }
+
+    #[test]
+    fn unix_nanos_round_trip() {
+        for &nanos in &[0, 99, 100, 1_234_567_891, 1_580_000_000_123_456_789, u64::MAX] {
+            assert_eq!(TimeStamp::from_unix_nanos(nanos).as_unix_nanos(), nanos / 100 * 100);
+        }
+        assert_eq!(TimeStamp::UNIX_EPOCH.as_unix_nanos(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn system_time_round_trip() {
+        let system = SystemTime::now();
+        let round_tripped = SystemTime::from(TimeStamp::from(system));
+
+        let loss = system.duration_since(round_tripped).unwrap();
+        assert!(loss < Duration::from_nanos(100), "lost {:?}", loss);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pre_epoch_system_time_saturates() {
+        let system = SystemTime::UNIX_EPOCH - Duration::from_secs(3);
+        let timestamp = TimeStamp::from(system);
+
+        assert_eq!(TimeStamp::UNIX_EPOCH.duration_since(timestamp).unwrap(), Duration::from_secs(3));
+        assert_eq!(SystemTime::from(timestamp), SystemTime::UNIX_EPOCH);
+
+        // Sub-100-nanosecond precision floors before the epoch just as it does after it.
+        let just_before = TimeStamp::from(SystemTime::UNIX_EPOCH - Duration::from_nanos(50));
+        assert_eq!(just_before, TimeStamp::UNIX_EPOCH - Duration::from_nanos(100));
+        assert_eq!(just_before, TimeStamp::from_proto_parts(0, -50));
+    }
+
+    #[cfg(all(feature = "std", target_pointer_width = "64"))]
+    #[test]
+    fn far_future_timestamp_converts_to_system_time() {
+        // Well past the year 2554 where `as_unix_nanos` saturates.
+        let timestamp = TimeStamp::from_intervals(i64::MAX);
+        let system = SystemTime::from(timestamp);
+
+        assert_eq!(system.duration_since(SystemTime::UNIX_EPOCH).unwrap(), timestamp.duration_since_epoch());
+        assert_eq!(TimeStamp::from(system), timestamp);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn out_of_range_system_time_saturates() {
+        // Not every platform's `SystemTime` reaches past the range of `TimeStamp`.
+        if let Some(far_future) = SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(1 << 40)) {
+            assert_eq!(TimeStamp::from(far_future), TimeStamp::from_intervals(i64::MAX));
+        }
+        if let Some(far_past) = SystemTime::UNIX_EPOCH.checked_sub(Duration::from_secs(1 << 40)) {
+            assert_eq!(TimeStamp::from(far_past), TimeStamp::from_intervals(i64::MIN));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn manual_clock_advances_exactly() {
+        let clock = ManualClock::new(TimeStamp::UNIX_EPOCH);
+        let source: &dyn Clock = &clock;
+        let start = source.now();
+
+        clock.advance(Duration::from_millis(250));
+
+        assert_eq!(source.now().duration_since(start).unwrap(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn sub_timestamp_is_signed() {
+        let earlier = TimeStamp::from_unix_nanos(1_000_000_000);
+        let later = earlier + Duration::from_millis(1500);
+
+        assert_eq!(later - earlier, 1_500_000_000);
+        assert_eq!(earlier - later, -1_500_000_000);
+        assert_eq!(later - later, 0);
+    }
+
+    #[cfg(all(feature = "serialize", feature = "std"))]
+    #[test]
+    fn serde_round_trip() {
+        let timestamp = TimeStamp::now();
+
+        let encoded: Vec<u8> = bincode::serialize(&timestamp).unwrap();
+        assert_eq!(encoded, bincode::serialize(&timestamp.as_unix_nanos()).unwrap());
+
+        let decoded: TimeStamp = bincode::deserialize(&encoded[..]).unwrap();
+        assert_eq!(timestamp, decoded);
+    }
+
+    #[test]
+    fn rfc3339_with_fractional_seconds() {
+        let timestamp = TimeStamp::from_unix_nanos(1_714_566_896_789_012_300);
+
+        assert_eq!(timestamp.to_string(), "2024-05-01T12:34:56.7890123Z");
+        assert_eq!("2024-05-01T12:34:56.789012345Z".parse(), Ok(timestamp));
+        assert_eq!("2024-05-01T14:34:56.7890123+02:00".parse(), Ok(timestamp));
+        assert_eq!(
+            "2024-05-01T12:34:56.789Z".parse::<TimeStamp>().unwrap().to_string(),
+            "2024-05-01T12:34:56.789Z"
+        );
+    }
+
+    #[test]
+    fn rfc3339_unix_epoch() {
+        assert_eq!(TimeStamp::UNIX_EPOCH.to_string(), "1970-01-01T00:00:00Z");
+        assert_eq!("1970-01-01T00:00:00Z".parse(), Ok(TimeStamp::UNIX_EPOCH));
+        assert_eq!(
+            (TimeStamp::UNIX_EPOCH - Duration::from_secs(2)).to_string(),
+            "1969-12-31T23:59:58Z"
+        );
+    }
+
+    #[test]
+    fn rfc3339_rejects_malformed() {
+        for input in &[
+            "",
+            "not a timestamp",
+            "2024-05-01T12:34:56",
+            "2024-05-01T12:34:56.Z",
+            "2024-02-30T12:34:56Z",
+            "2024-05-01 12:34:56Z",
+            "2024-05-01T12:34:56+0200",
+            "2024-05-01T24:00:00Z",
+        ] {
+            assert_eq!(input.parse::<TimeStamp>(), Err(ParseTimeStampError(())), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn saturating_arithmetic_clamps() {
+        let max = TimeStamp(KrazyKraigTime::from_intervals(i64::MAX));
+        let min = TimeStamp(KrazyKraigTime::from_intervals(i64::MIN));
+
+        assert_eq!(TimeStamp::UNIX_EPOCH.saturating_add(Duration::MAX), max);
+        assert_eq!(max.saturating_add(Duration::from_nanos(100)), max);
+        assert_eq!(TimeStamp::UNIX_EPOCH.saturating_sub(Duration::MAX), min);
+        assert_eq!(min.saturating_add(Duration::MAX), max);
+        assert_eq!(
+            TimeStamp::UNIX_EPOCH.saturating_add(Duration::from_secs(5)),
+            TimeStamp::UNIX_EPOCH + Duration::from_secs(5)
+        );
+        assert_eq!(
+            TimeStamp::UNIX_EPOCH.saturating_sub(Duration::from_secs(5)),
+            TimeStamp::UNIX_EPOCH - Duration::from_secs(5)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn now_coarse_tracks_now() {
+        for _ in 0..1000 {
+            let before = TimeStamp::now();
+            let coarse = TimeStamp::now_coarse();
+            let after = TimeStamp::now();
+
+            assert!(coarse <= after);
+            assert!(before.duration_since(coarse).unwrap_or_default() < Duration::from_millis(50));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mono_instant_is_non_decreasing() {
+        let mut previous = MonoInstant::now();
+        for _ in 0..1000 {
+            let next = MonoInstant::now();
+            assert!(next >= previous);
+            previous = next;
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mono_instant_duration_since_saturates() {
+        // Only checks that an earlier reading measured against a later one floors at zero; the
+        // system clock cannot be stepped from a test.
+        let later = MonoInstant::now();
+        let earlier = MonoInstant(later.0 - Duration::from_nanos(1));
+        assert_eq!(earlier.duration_since(later), Duration::from_secs(0));
+        assert_eq!(later.duration_since(earlier), Duration::from_nanos(1));
+    }
+
+    #[test]
+    fn reference_operators_match_by_value() {
+        let timestamp = TimeStamp::from_unix_nanos(1_580_000_000_123_456_700);
+        let reference = &timestamp;
+        let delta = Duration::from_micros(1500);
+
+        assert_eq!(reference + delta, timestamp + delta);
+        assert_eq!(reference - delta, timestamp - delta);
+        assert_eq!(reference - (timestamp - delta), timestamp - (timestamp - delta));
+        assert_eq!(reference - (timestamp + delta), -1_500_000);
+    }
+
+    #[test]
+    fn proto_parts_normalize_nanos() {
+        assert_eq!(TimeStamp::from_proto_parts(10, 1_500_000_000), TimeStamp::from_proto_parts(11, 500_000_000));
+        assert_eq!(TimeStamp::from_proto_parts(10, 1_500_000_000).to_proto_parts(), (11, 500_000_000));
+        assert_eq!(TimeStamp::from_proto_parts(10, -250_000_000).to_proto_parts(), (9, 750_000_000));
+        assert_eq!(TimeStamp::from_proto_parts(0, 0), TimeStamp::UNIX_EPOCH);
+        assert_eq!(
+            (TimeStamp::UNIX_EPOCH - Duration::from_millis(1500)).to_proto_parts(),
+            (-2, 500_000_000)
+        );
+    }
+
+    #[test]
+    fn proto_parts_round_trip() {
+        let timestamp = TimeStamp::from_unix_nanos(1_580_000_000_123_456_700);
+        let (seconds, nanos) = timestamp.to_proto_parts();
+
+        assert_eq!((seconds, nanos), (1_580_000_000, 123_456_700));
+        assert_eq!(TimeStamp::from_proto_parts(seconds, nanos), timestamp);
+    }
+
+    #[test]
+    fn equal_instants_are_interchangeable_keys() {
+        use std::collections::HashMap;
+
+        let nanos = 1_580_000_000_123_456_700;
+        let mut spans = HashMap::new();
+        spans.insert(TimeStamp::from_unix_nanos(nanos), "span");
+
+        let same_instant = TimeStamp::from_intervals(INTERVALS_TO_UNIX_EPOCH as i64 + (nanos / 100) as i64);
+        assert_eq!(same_instant, TimeStamp::from_unix_nanos(nanos));
+        assert_eq!(spans.get(&same_instant), Some(&"span"));
+
+        let before_epoch = TimeStamp::UNIX_EPOCH - Duration::from_secs(1);
+        assert_ne!(before_epoch, TimeStamp::UNIX_EPOCH - Duration::from_secs(2));
+        assert!(before_epoch < TimeStamp::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn default_is_unix_epoch() {
+        assert_eq!(TimeStamp::default(), TimeStamp::UNIX_EPOCH);
+        assert_eq!(TimeStamp::from_intervals(INTERVALS_TO_UNIX_EPOCH as i64), TimeStamp::UNIX_EPOCH);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_round_trip() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let time = Utc.timestamp_opt(1_580_000_000, 123_456_700).unwrap();
+        let timestamp = TimeStamp::from(time);
+        assert_eq!(timestamp.as_unix_nanos(), 1_580_000_000_123_456_700);
+        assert_eq!(DateTime::<Utc>::from(timestamp), time);
+
+        let before_epoch = Utc.timestamp_opt(-3, 123_456_700).unwrap();
+        let timestamp = TimeStamp::from(before_epoch);
+        assert!(timestamp < TimeStamp::UNIX_EPOCH);
+        assert_eq!(DateTime::<Utc>::from(timestamp), before_epoch);
+    }
+
+    #[test]
+    fn pre_unix_epoch_timestamps() {
+        let before_epoch = TimeStamp::from_intervals(INTERVALS_TO_UNIX_EPOCH as i64 - 3 * INTERVALS_PER_SEC as i64);
+        assert!(before_epoch < TimeStamp::UNIX_EPOCH);
+
+        assert_eq!(before_epoch.signed_duration_since(TimeStamp::UNIX_EPOCH), -3_000_000_000);
+        assert_eq!(TimeStamp::UNIX_EPOCH.signed_duration_since(before_epoch), 3_000_000_000);
+        assert_eq!(before_epoch.as_unix_nanos(), 0);
+
+        let before_1601 = TimeStamp::from_intervals(-(INTERVALS_PER_SEC as i64));
+        assert_eq!(
+            before_1601.signed_duration_since(TimeStamp::from_intervals(0)),
+            -1_000_000_000
+        );
+        assert_eq!(before_1601.as_unix_nanos(), 0);
+    }
+
+    #[test]
+    fn intervals_round_trip() {
+        for &intervals in &[
+            i64::MIN,
+            i64::MIN + 1,
+            -(1 << 32),
+            -(1 << 31),
+            -1,
+            0,
+            1,
+            0x7FFF_FFFF,
+            0x8000_0000,
+            0xFFFF_FFFF,
+            1 << 32,
+            INTERVALS_TO_UNIX_EPOCH as i64,
+            0x0123_4567_89AB_CDEF,
+            i64::MAX - 1,
+            i64::MAX,
+        ] {
+            assert_eq!(KrazyKraigTime::from_intervals(intervals).intervals(), intervals);
+        }
+
+        let min = KrazyKraigTime::from_intervals(i64::MIN);
+        let max = KrazyKraigTime::from_intervals(i64::MAX);
+        assert_eq!(max.sub_time(&min), Ok(intervals2dur(u64::MAX)));
+        assert_eq!(min.sub_time(&max), Err(intervals2dur(u64::MAX)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn unix_seconds_and_subsec_nanos_are_consistent() {
+        let now = TimeStamp::now();
+        let nanos = now.unix_seconds() as u64 * NANOS_PER_SEC + u64::from(now.subsec_nanos());
+
+        assert_eq!(nanos, now.as_unix_nanos());
+        assert_eq!(now.duration_since_epoch(), Duration::from_nanos(nanos));
+
+        let before_epoch = TimeStamp::UNIX_EPOCH - Duration::from_millis(1500);
+        assert_eq!(before_epoch.unix_seconds(), -2);
+        assert_eq!(before_epoch.subsec_nanos(), 500_000_000);
+        assert_eq!(before_epoch.duration_since_epoch(), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn system_time_error_reports_magnitude() {
+        let earlier = TimeStamp::UNIX_EPOCH;
+        let later = earlier + Duration::from_millis(1500);
+        let err = earlier.duration_since(later).unwrap_err();
+
+        assert_eq!(err.duration(), Duration::from_millis(1500));
+        assert_eq!(err.to_string(), "second time provided was later than self by 1.5s");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn now_advances_with_system_time() {
+        let first = KrazyKraigTime::now();
+        std::thread::sleep(Duration::from_millis(2));
+        let system = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap();
+        let second = KrazyKraigTime::now();
+
+        assert!(second > first);
+
+        let ours = second.sub_time(&UNIX_EPOCH).unwrap();
+        let skew = ours.abs_diff(system);
+        assert!(skew < Duration::from_secs(1), "skew of {:?} against SystemTime", skew);
+    }
+}
@@ -1,7 +1,9 @@
 //! OpenTelemetry shared core date types
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 /// Key used for metric `LabelSet`s and trace `Span` attributes.
 #[cfg_attr(feature = "serialize", derive(Deserialize, PartialEq, Serialize))]
@@ -183,11 +185,10 @@ impl Unit {
     }
 }
 
-#[cfg(target_os = "windows")]
 mod clock;
 
-#[cfg(target_os = "windows")]
-pub type TimeStamp = clock::TimeStamp;
+pub use clock::{Clock, ParseTimeStampError, SystemTimeError};
+#[cfg(feature = "std")]
+pub use clock::{ManualClock, MonoInstant, SystemClock};
 
-#[cfg(not(target_os = "windows"))]
-pub type TimeStamp = std::time::SystemTime;
+pub type TimeStamp = clock::TimeStamp;
@@ -14,11 +14,19 @@
 //! which implements the delivery of the telemetry. The application must also configure exporters
 //! so that the SDK knows where and how to deliver the telemetry.
 pub mod core;
+#[cfg(feature = "std")]
 pub mod metrics;
+#[cfg(feature = "std")]
 pub mod propagation;
+#[cfg(feature = "std")]
 pub mod trace;
 
-pub use self::core::{Key, KeyValue, Unit, Value, TimeStamp};
+pub use self::core::{
+    Clock, Key, KeyValue, ParseTimeStampError, SystemTimeError, TimeStamp, Unit, Value,
+};
+#[cfg(feature = "std")]
+pub use self::core::{ManualClock, MonoInstant, SystemClock};
+#[cfg(feature = "std")]
 pub use metrics::{
     counter::{Counter, CounterHandle},
     gauge::{Gauge, GaugeHandle},
@@ -27,7 +35,9 @@ pub use metrics::{
     value::MeasurementValue,
     Instrument, InstrumentHandle, LabelSet, Measurement, Meter, MetricOptions,
 };
+#[cfg(feature = "std")]
 pub use propagation::{binary_propagator::BinaryFormat, text_propagator::HttpTextFormat, Carrier};
+#[cfg(feature = "std")]
 pub use trace::{
     b3_propagator::B3Propagator,
     event::Event,
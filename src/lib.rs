@@ -207,8 +207,14 @@
 // ... and unreachable pub
 #![deny(missing_debug_implementations)]
 #![cfg_attr(test, deny(warnings))]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 pub mod api;
+#[cfg(feature = "std")]
 pub mod exporter;
+#[cfg(feature = "std")]
 pub mod global;
+#[cfg(feature = "std")]
 pub mod sdk;
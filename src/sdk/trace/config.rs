@@ -3,6 +3,7 @@
 //! Configuration represents the global tracing configuration, overrides
 //! can be set for the default OpenTelemetry limits and Sampler.
 use crate::{api, sdk};
+use std::sync::Arc;
 
 /// Tracer configuration
 #[derive(Debug)]
@@ -15,6 +16,8 @@ pub struct Config {
     pub max_attributes_per_span: u32,
     /// The max links that can be added to a `Span`.
     pub max_links_per_span: u32,
+    /// The clock used to timestamp `Span`s and their events.
+    pub clock: Arc<dyn api::Clock>,
 }
 
 impl Default for Config {
@@ -25,6 +28,7 @@ impl Default for Config {
             max_events_per_span: 128,
             max_attributes_per_span: 32,
             max_links_per_span: 32,
+            clock: Arc::new(api::SystemClock),
         }
     }
 }
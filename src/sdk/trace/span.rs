@@ -58,6 +58,11 @@ impl Span {
             .and_then(|inner| inner.lock().ok().map(|span_data| f(&span_data)))
     }
 
+    /// Current time according to the provider's configured clock
+    fn now(&self) -> TimeStamp {
+        self.inner.tracer.provider().config().clock.now()
+    }
+
     /// Operate on mutable reference to span inner
     fn with_data_mut<T, F>(&self, f: F) -> Option<T>
     where
@@ -71,6 +76,12 @@ impl Span {
 }
 
 impl api::Span for Span {
+    /// Records events in the context of a given `Span`, timestamped by the provider's clock.
+    fn add_event(&mut self, message: String) {
+        let timestamp = self.now();
+        self.add_event_with_timestamp(message, timestamp)
+    }
+
     /// Records events at a specific time in the context of a given `Span`.
     ///
     /// Note that the OpenTelemetry project documents certain ["standard event names and
@@ -130,8 +141,9 @@ impl api::Span for Span {
 
     /// Finishes the span.
     fn end(&mut self) {
+        let end_time = self.now();
         self.with_data_mut(|data| {
-            data.end_time = TimeStamp::now();
+            data.end_time = end_time;
         });
     }
 
@@ -157,7 +169,7 @@ impl Drop for SpanInner {
         if let Some(data) = self.data.take() {
             if let Ok(mut inner) = data.lock() {
                 if inner.end_time == inner.start_time {
-                    inner.end_time = TimeStamp::now();
+                    inner.end_time = self.tracer.provider().config().clock.now();
                 }
                 let exportable_span = Arc::new(inner.clone());
                 for processor in self.tracer.provider().span_processors() {
@@ -167,3 +179,35 @@ impl Drop for SpanInner {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::{Provider, Span as _};
+    use std::time::Duration;
+
+    #[test]
+    fn span_timing_uses_configured_clock() {
+        let clock = Arc::new(api::ManualClock::new(TimeStamp::UNIX_EPOCH));
+        let provider = sdk::Provider::builder()
+            .with_config(sdk::Config {
+                clock: clock.clone(),
+                ..Default::default()
+            })
+            .build();
+        let mut span = provider.get_tracer("test").start("operation", None);
+
+        clock.advance(Duration::from_millis(250));
+        span.add_event("halfway".to_string());
+        clock.advance(Duration::from_millis(250));
+        span.end();
+
+        span.with_data(|data| {
+            assert_eq!(data.start_time, TimeStamp::UNIX_EPOCH);
+            assert_eq!(data.end_time.duration_since(data.start_time).unwrap(), Duration::from_millis(500));
+            let event = data.message_events.iter().next().unwrap();
+            assert_eq!(event.timestamp.duration_since(data.start_time).unwrap(), Duration::from_millis(250));
+        })
+        .unwrap();
+    }
+}
@@ -7,7 +7,7 @@
 //! and exposes methods for creating and activating new `Spans`.
 //!
 //! Docs: https://github.com/open-telemetry/opentelemetry-specification/blob/master/specification/api-tracing.md#tracer
-use crate::api::{Span, TimeStamp};
+use crate::api::Span;
 use crate::sdk;
 use crate::{api, exporter};
 use std::cell::RefCell;
@@ -145,14 +145,15 @@ impl api::Tracer for Tracer {
             attributes.append_vec(&mut attribute_options);
             let mut links = sdk::EvictedQueue::new(config.max_links_per_span);
             links.append_vec(&mut link_options);
+            let start_time = config.clock.now();
 
             exporter::trace::SpanData {
                 context: api::SpanContext::new(trace_id, span_id, trace_flags, false),
                 parent_span_id,
                 span_kind,
                 name: name.to_string(),
-                start_time: TimeStamp::now(),
-                end_time: TimeStamp::now(),
+                start_time,
+                end_time: start_time,
                 attributes,
                 message_events: sdk::EvictedQueue::new(config.max_events_per_span),
                 links,
@@ -0,0 +1,22 @@
+//! Exercises the `opentelemetry` timestamp types with default features, and so `std`, disabled.
+//!
+//! Test with `cargo test -p no-std-check` so other workspace members do not switch `std` back on.
+#![cfg_attr(not(test), no_std)]
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+    use opentelemetry::api::TimeStamp;
+
+    #[test]
+    fn unix_epoch_arithmetic() {
+        let epoch = TimeStamp::UNIX_EPOCH;
+        let later = epoch.checked_add(Duration::from_millis(1500)).unwrap();
+
+        assert_eq!(later.as_unix_nanos(), 1_500_000_000);
+        assert_eq!(later, TimeStamp::from_unix_nanos(1_500_000_000));
+        assert_eq!(later.duration_since(epoch).unwrap(), Duration::from_millis(1500));
+        assert_eq!(later - Duration::from_millis(1500), epoch);
+        assert_eq!(epoch.saturating_sub(Duration::from_secs(3)).signed_duration_since(epoch), -3_000_000_000);
+    }
+}